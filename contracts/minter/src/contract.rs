@@ -1,6 +1,13 @@
 use crate::error::ContractError;
-use crate::msg::{ConfigResponse, ExecuteMsg, InstantiateMsg, MinterQueryMsg, RoyaltiesInfoResponse};
-use crate::state::{Config, CONFIG, CW721_ADDRESS, MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_IDS};
+use crate::msg::{
+    ConfigResponse, EditionMint, ExecuteMsg, InstantiateMsg, MinterQueryMsg, RoyaltiesInfoResponse,
+};
+use crate::state::{
+    Config, MintPhase, PendingRandomMint, CONFIG, CW721_ADDRESS, EDITION_REMAINING_SUPPLY,
+    ENTROPY_ACC, ENTROPY_FOLD_COUNT, ENTROPY_FOLD_SENDERS, MINTABLE_NUM_TOKENS, MINTABLE_TOKEN_IDS,
+    MINTABLE_TOKEN_POSITIONS, MINTABLE_TOKEN_POSITION_OF, MINT_PHASES, PENDING_RANDOM_MINTS,
+    PHASE_MINT_COUNT, RANDOM_MINT_NONCE,
+};
 use crate::{Deserialize, Serialize};
 use crate::{Extension, JsonSchema, Metadata};
 #[cfg(not(feature = "library"))]
@@ -9,9 +16,12 @@ use cosmwasm_std::{
     to_binary, Addr, Binary, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo, Order,
     Reply, ReplyOn, Response, StdResult, SubMsg, Uint128, WasmMsg,
 };
+use cw1155_base::msg::{ExecuteMsg as Cw1155ExecuteMsg, InstantiateMsg as Cw1155InstantiateMsg};
 use cw2::set_contract_version;
 use cw721_base::{ExecuteMsg as Cw721ExecuteMsg, InstantiateMsg as Cw721InstantiateMsg, MintMsg};
+use cw_storage_plus::Bound;
 use cw_utils::parse_reply_instantiate_data;
+use sha2::{Digest, Sha256};
 use url::Url;
 
 pub type Cw721ArtaverseContract<'a> = cw721_base::Cw721Contract<'a, Extension, Empty>;
@@ -24,6 +34,14 @@ const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub(crate) const MAX_TOKEN_LIMIT: u32 = 10000;
 pub(crate) const MAX_TOKEN_PER_BATCH_LIMIT: u32 = 200;
 pub(crate) const INSTANTIATE_CW721_REPLY_ID: u64 = 1;
+pub(crate) const INSTANTIATE_CW1155_REPLY_ID: u64 = 2;
+pub(crate) const DEFAULT_MINTABLE_TOKENS_LIMIT: u32 = 30;
+pub(crate) const MAX_MINTABLE_TOKENS_LIMIT: u32 = 100;
+pub(crate) const MAX_ROYALTY_PERCENTAGE: u64 = 100;
+// Minimum number of distinct senders other than the committer folded in since
+// commit before RevealMint is allowed.
+pub(crate) const MIN_OTHER_SENDERS_SINCE_COMMIT: u64 = 2;
+pub(crate) const ENTROPY_FOLD_LOG_CAPACITY: u64 = 32;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
 pub struct TokensResponse {
@@ -33,6 +51,16 @@ pub struct TokensResponse {
     pub tokens: Vec<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct MintableTokensResponse {
+    /// Remaining mintable token ids in ascending order.
+    /// If there are more than `limit`, use the last id as `start_after`
+    /// in the next query to achieve pagination.
+    pub tokens: Vec<u32>,
+    /// Total number of unminted tokens left in the collection.
+    pub count: u32,
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -76,10 +104,21 @@ pub fn instantiate(
         return Err(ContractError::InvalidBaseTokenURI {});
     }
 
+    if let Some(royalty_percentage) = msg.royalty_percentage {
+        if royalty_percentage > MAX_ROYALTY_PERCENTAGE {
+            return Err(ContractError::InvalidRoyaltyPercentage {
+                min: 0,
+                max: MAX_ROYALTY_PERCENTAGE,
+            });
+        }
+    }
+
     let config = Config {
         owner: info.sender.clone(),
         cw721_code_id: msg.cw721_code_id,
         cw721_address: None,
+        cw1155_code_id: msg.cw1155_code_id,
+        cw1155_address: None,
         name: msg.name.clone(),
         symbol: msg.symbol.clone(),
         base_token_uri: msg.base_token_uri.clone(),
@@ -88,17 +127,57 @@ pub fn instantiate(
         max_tokens_per_batch_transfer: msg.max_tokens_per_batch_transfer,
         royalty_percentage: msg.royalty_percentage,
         royalty_payment_address: msg.royalty_payment_address,
+        random_mint_enabled: msg.random_mint_enabled,
     };
     CONFIG.save(deps.storage, &config)?;
     MINTABLE_NUM_TOKENS.save(deps.storage, &msg.num_tokens)?;
+    if config.random_mint_enabled {
+        RANDOM_MINT_NONCE.save(deps.storage, &0u64)?;
+        ENTROPY_ACC.save(
+            deps.storage,
+            &Sha256::digest(env.contract.address.as_bytes()).to_vec(),
+        )?;
+        ENTROPY_FOLD_COUNT.save(deps.storage, &0u64)?;
+    }
+
+    // Validate and save the ordered mint phases, if any. Each phase must
+    // close before the next one opens so that exactly one phase (or none)
+    // is ever active at a given block time.
+    let phases: Vec<MintPhase> = msg
+        .mint_phases
+        .unwrap_or_default()
+        .into_iter()
+        .map(|phase| MintPhase {
+            start_time: phase.start_time,
+            end_time: phase.end_time,
+            max_tokens_per_address: phase.max_tokens_per_address,
+        })
+        .collect();
+    for window in phases.windows(2) {
+        if window[0].end_time > window[1].start_time {
+            return Err(ContractError::InvalidMintPhases {});
+        }
+    }
+    for phase in &phases {
+        if phase.start_time >= phase.end_time {
+            return Err(ContractError::InvalidMintPhases {});
+        }
+    }
+    MINT_PHASES.save(deps.storage, &phases)?;
 
-    // Save mintable token ids map
+    // Save mintable token ids map, and the position array backing random
+    // draws when `random_mint_enabled` is set.
     for token_id in 1..=msg.num_tokens {
         MINTABLE_TOKEN_IDS.save(deps.storage, token_id, &true)?;
+        if config.random_mint_enabled {
+            let position = token_id - 1;
+            MINTABLE_TOKEN_POSITIONS.save(deps.storage, position, &token_id)?;
+            MINTABLE_TOKEN_POSITION_OF.save(deps.storage, token_id, &position)?;
+        }
     }
 
     // Sub-message to instantiate cw721 contract
-    let sub_msgs: Vec<SubMsg> = vec![SubMsg {
+    let mut sub_msgs: Vec<SubMsg> = vec![SubMsg {
         id: INSTANTIATE_CW721_REPLY_ID,
         msg: WasmMsg::Instantiate {
             admin: Some(info.sender.to_string()),
@@ -116,6 +195,41 @@ pub fn instantiate(
         reply_on: ReplyOn::Success,
     }];
 
+    // Seed the edition pool and queue the cw1155 instantiation alongside the
+    // cw721 collection. A non-empty `editions` list with no `cw1155_code_id`
+    // would record edition supply but never instantiate the cw1155 contract
+    // that backs it, permanently breaking `MintEdition`/`BatchMintEdition`.
+    if let Some(editions) = msg.editions {
+        if !editions.is_empty() && config.cw1155_code_id.is_none() {
+            return Err(ContractError::MissingCw1155CodeId {});
+        }
+
+        for (token_id, max_supply) in editions {
+            if max_supply.is_zero() {
+                return Err(ContractError::InvalidEditionAmount {});
+            }
+            EDITION_REMAINING_SUPPLY.save(deps.storage, token_id, &max_supply)?;
+        }
+
+        if let Some(cw1155_code_id) = config.cw1155_code_id {
+            sub_msgs.push(SubMsg {
+                id: INSTANTIATE_CW1155_REPLY_ID,
+                msg: WasmMsg::Instantiate {
+                    admin: Some(info.sender.to_string()),
+                    code_id: cw1155_code_id,
+                    msg: to_binary(&Cw1155InstantiateMsg {
+                        minter: env.contract.address.to_string(),
+                    })?,
+                    funds: vec![],
+                    label: String::from("Check CW1155"),
+                }
+                .into(),
+                gas_limit: None,
+                reply_on: ReplyOn::Success,
+            });
+        }
+    }
+
     Ok(Response::new()
         .add_attribute("method", "instantiate")
         .add_attribute("owner", info.sender)
@@ -126,18 +240,27 @@ pub fn instantiate(
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
-    _env: Env,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
+    // Only fold entropy for collections that opted into random minting.
+    if CONFIG.load(deps.storage)?.random_mint_enabled {
+        _fold_entropy(deps.branch(), &env, &info)?;
+    }
+
     match msg {
-        ExecuteMsg::Mint { token_id } => execute_mint_sender(deps, info, token_id),
-        ExecuteMsg::BatchMint { token_ids } => execute_batch_mint_sender(deps, info, token_ids),
+        ExecuteMsg::Mint { token_id } => execute_mint_sender(deps, env, info, token_id),
+        ExecuteMsg::BatchMint { token_ids } => {
+            execute_batch_mint_sender(deps, env, info, token_ids)
+        }
         ExecuteMsg::MintTo {
             token_id,
             recipient,
-        } => execute_mint_to(deps, info, recipient, token_id),
+        } => execute_mint_to(deps, env, info, recipient, token_id),
+        ExecuteMsg::RevealMint { committer } => execute_reveal_mint(deps, env, info, committer),
+        ExecuteMsg::CancelRandomMint {} => execute_cancel_random_mint(deps, info),
         ExecuteMsg::TransferNft {
             recipient,
             token_id,
@@ -146,35 +269,94 @@ pub fn execute(
             recipient,
             token_ids,
         } => execute_batch_transfer_nft(deps, info, recipient, token_ids),
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => execute_send_nft(deps, info, contract, token_id, msg),
+        ExecuteMsg::BatchSendNft {
+            contract,
+            token_ids,
+            msg,
+        } => execute_batch_send_nft(deps, info, contract, token_ids, msg),
+        ExecuteMsg::MintEdition {
+            token_id,
+            amount,
+            recipient,
+        } => _execute_mint_edition(deps, info, token_id, amount, recipient),
+        ExecuteMsg::BatchMintEdition { mints } => _execute_batch_mint_edition(deps, info, mints),
+        ExecuteMsg::UpdateConfig {
+            max_tokens_per_batch_mint,
+            max_tokens_per_batch_transfer,
+        } => execute_update_config(
+            deps,
+            info,
+            max_tokens_per_batch_mint,
+            max_tokens_per_batch_transfer,
+        ),
+        ExecuteMsg::UpdateRoyalties {
+            royalty_percentage,
+            royalty_payment_address,
+        } => execute_update_royalties(deps, info, royalty_percentage, royalty_payment_address),
+        ExecuteMsg::TransferOwnership { new_owner } => {
+            execute_transfer_ownership(deps, info, new_owner)
+        }
     }
 }
 
 pub fn execute_mint_sender(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
-    token_id: u32,
+    token_id: Option<u32>,
 ) -> Result<Response, ContractError> {
     let recipient = info.sender.clone();
-    _execute_mint(deps, info, Some(recipient), Some(token_id))
+    _execute_mint(deps, env, info, Some(recipient), token_id)
 }
 
 pub fn execute_batch_mint_sender(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     token_ids: Vec<u32>,
 ) -> Result<Response, ContractError> {
     let recipient = info.sender.clone();
-    _execute_batch_mint(deps, info, Some(recipient), token_ids)
+    _execute_batch_mint(deps, env, info, Some(recipient), token_ids)
 }
 
 pub fn execute_mint_to(
     deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     recipient: String,
-    token_id: u32,
+    token_id: Option<u32>,
 ) -> Result<Response, ContractError> {
     let recipient = deps.api.addr_validate(&recipient)?;
-    _execute_mint(deps, info, Some(recipient), Some(token_id))
+    _execute_mint(deps, env, info, Some(recipient), token_id)
+}
+
+pub fn execute_reveal_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    committer: String,
+) -> Result<Response, ContractError> {
+    let committer_addr = deps.api.addr_validate(&committer)?;
+    _execute_reveal_mint(deps, env, info, committer_addr)
+}
+
+pub fn execute_cancel_random_mint(
+    deps: DepsMut,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    if !PENDING_RANDOM_MINTS.has(deps.storage, &info.sender) {
+        return Err(ContractError::NoPendingRandomMint {});
+    }
+    PENDING_RANDOM_MINTS.remove(deps.storage, &info.sender);
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_random_mint")
+        .add_attribute("sender", info.sender))
 }
 
 pub fn execute_transfer_nft(
@@ -197,11 +379,224 @@ pub fn execute_batch_transfer_nft(
     _execute_batch_transfer_nft(deps, info, recipient, token_ids)
 }
 
+pub fn execute_send_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract: String,
+    token_id: u32,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract_addr = deps.api.addr_validate(&contract)?;
+    _execute_send_nft(deps, info, contract_addr, token_id, msg)
+}
+
+pub fn execute_batch_send_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract: String,
+    token_ids: Vec<u32>,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let contract_addr = deps.api.addr_validate(&contract)?;
+    _execute_batch_send_nft(deps, info, contract_addr, token_ids, msg)
+}
+
+pub fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    max_tokens_per_batch_mint: Option<u32>,
+    max_tokens_per_batch_transfer: Option<u32>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(max_tokens_per_batch_mint) = max_tokens_per_batch_mint {
+        if max_tokens_per_batch_mint == 0 || max_tokens_per_batch_mint > MAX_TOKEN_PER_BATCH_LIMIT {
+            return Err(ContractError::InvalidMaxTokensPerBatchMint {
+                min: 1,
+                max: MAX_TOKEN_PER_BATCH_LIMIT,
+            });
+        }
+        config.max_tokens_per_batch_mint = max_tokens_per_batch_mint;
+    }
+
+    if let Some(max_tokens_per_batch_transfer) = max_tokens_per_batch_transfer {
+        if max_tokens_per_batch_transfer == 0
+            || max_tokens_per_batch_transfer > MAX_TOKEN_PER_BATCH_LIMIT
+        {
+            return Err(ContractError::InvalidMaxTokensPerBatchTransfer {
+                min: 1,
+                max: MAX_TOKEN_PER_BATCH_LIMIT,
+            });
+        }
+        config.max_tokens_per_batch_transfer = max_tokens_per_batch_transfer;
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_config")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_update_royalties(
+    deps: DepsMut,
+    info: MessageInfo,
+    royalty_percentage: Option<u64>,
+    royalty_payment_address: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    if let Some(royalty_percentage) = royalty_percentage {
+        if royalty_percentage > MAX_ROYALTY_PERCENTAGE {
+            return Err(ContractError::InvalidRoyaltyPercentage {
+                min: 0,
+                max: MAX_ROYALTY_PERCENTAGE,
+            });
+        }
+        config.royalty_percentage = Some(royalty_percentage);
+    }
+    if let Some(royalty_payment_address) = royalty_payment_address {
+        deps.api.addr_validate(&royalty_payment_address)?;
+        config.royalty_payment_address = Some(royalty_payment_address);
+    }
+
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_royalties")
+        .add_attribute("sender", info.sender))
+}
+
+pub fn execute_transfer_ownership(
+    deps: DepsMut,
+    info: MessageInfo,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let mut config = CONFIG.load(deps.storage)?;
+    if info.sender != config.owner {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let new_owner_addr = deps.api.addr_validate(&new_owner)?;
+    config.owner = new_owner_addr;
+    CONFIG.save(deps.storage, &config)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_ownership")
+        .add_attribute("sender", info.sender)
+        .add_attribute("new_owner", new_owner))
+}
+
+fn _execute_mint_edition(
+    deps: DepsMut,
+    info: MessageInfo,
+    token_id: u32,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
+
+    let msg = _mint_edition(deps, &config, token_id, amount, &recipient_addr)?;
+
+    Ok(Response::new()
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient_addr)
+        .add_attribute("token_id", token_id.to_string())
+        .add_attribute("amount", amount)
+        .add_message(msg))
+}
+
+fn _execute_batch_mint_edition(
+    mut deps: DepsMut,
+    info: MessageInfo,
+    mints: Vec<EditionMint>,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut count: u32 = 0;
+    let mut minted_token_ids: Vec<u32> = vec![];
+    let mut msgs: Vec<CosmosMsg<Empty>> = vec![];
+    for mint in &mints {
+        if count >= config.max_tokens_per_batch_mint {
+            break;
+        }
+
+        let recipient_addr = deps.api.addr_validate(&mint.recipient)?;
+        let msg = _mint_edition(
+            deps.branch(),
+            &config,
+            mint.token_id,
+            mint.amount,
+            &recipient_addr,
+        )?;
+        msgs.push(msg);
+
+        minted_token_ids.push(mint.token_id);
+        count += 1;
+    }
+
+    Ok(Response::new()
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", format!("{:?}", minted_token_ids))
+        .add_messages(msgs))
+}
+
+/// Decrements `token_id`'s remaining edition supply and builds the cw1155
+/// `Mint` message. Shared by `MintEdition` and `BatchMintEdition`.
+fn _mint_edition(
+    deps: DepsMut,
+    config: &Config,
+    token_id: u32,
+    amount: Uint128,
+    recipient_addr: &Addr,
+) -> Result<CosmosMsg, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidEditionAmount {});
+    }
+
+    let remaining = EDITION_REMAINING_SUPPLY
+        .may_load(deps.storage, token_id)?
+        .ok_or(ContractError::InvalidEditionTokenId { token_id })?;
+    if remaining < amount {
+        return Err(ContractError::EditionSoldOut { token_id });
+    }
+    EDITION_REMAINING_SUPPLY.save(deps.storage, token_id, &(remaining - amount))?;
+
+    let cw1155_address = config
+        .cw1155_address
+        .as_ref()
+        .ok_or(ContractError::InvalidEditionTokenId { token_id })?;
+
+    let mint_msg = Cw1155ExecuteMsg::Mint {
+        to: recipient_addr.to_string(),
+        token_id: token_id.to_string(),
+        value: amount,
+        msg: None,
+    };
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: cw1155_address.to_string(),
+        msg: to_binary(&mint_msg)?,
+        funds: vec![],
+    }))
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: MinterQueryMsg) -> StdResult<Binary> {
     match msg {
         MinterQueryMsg::GetConfig {} => to_binary(&query_config(deps)?),
-        MinterQueryMsg::RoyaltyInfo { sale_price } => to_binary(&query_royalties_info(deps, sale_price)?),
+        MinterQueryMsg::RoyaltyInfo { sale_price } => {
+            to_binary(&query_royalties_info(deps, sale_price)?)
+        }
+        MinterQueryMsg::MintableTokens { start_after, limit } => {
+            to_binary(&query_mintable_tokens(deps, start_after, limit)?)
+        }
         _ => Cw721ArtaverseContract::default().query(deps, env, msg.into()),
     }
 }
@@ -212,6 +607,8 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
         owner: config.owner,
         cw721_code_id: config.cw721_code_id,
         cw721_address: config.cw721_address,
+        cw1155_code_id: config.cw1155_code_id,
+        cw1155_address: config.cw1155_address,
         max_tokens: config.max_tokens,
         max_tokens_per_mint: config.max_tokens_per_batch_mint,
         max_tokens_per_batch_transfer: config.max_tokens_per_batch_transfer,
@@ -223,16 +620,173 @@ fn query_config(deps: Deps) -> StdResult<ConfigResponse> {
             royalty_payment_address: config.royalty_payment_address,
             ..Metadata::default()
         }),
+        random_mint_enabled: config.random_mint_enabled,
     })
 }
 
-fn _execute_batch_mint(
+fn query_mintable_tokens(
+    deps: Deps,
+    start_after: Option<u32>,
+    limit: Option<u32>,
+) -> StdResult<MintableTokensResponse> {
+    let limit = limit
+        .unwrap_or(DEFAULT_MINTABLE_TOKENS_LIMIT)
+        .min(MAX_MINTABLE_TOKENS_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let tokens: Vec<u32> = MINTABLE_TOKEN_IDS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<u32>>>()?;
+    let count = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+
+    Ok(MintableTokensResponse { tokens, count })
+}
+
+/// Returns the phase active at `env.block.time`, or `None` if the
+/// collection has no configured phases (minting is unconditionally open).
+fn active_mint_phase(deps: Deps, env: &Env) -> Result<Option<(u8, MintPhase)>, ContractError> {
+    let phases = MINT_PHASES.load(deps.storage)?;
+    if phases.is_empty() {
+        return Ok(None);
+    }
+    if env.block.time < phases[0].start_time {
+        return Err(ContractError::MintingNotStarted {});
+    }
+    if env.block.time >= phases[phases.len() - 1].end_time {
+        return Err(ContractError::MintingEnded {});
+    }
+    for (idx, phase) in phases.iter().enumerate() {
+        if env.block.time >= phase.start_time && env.block.time < phase.end_time {
+            return Ok(Some((idx as u8, phase.clone())));
+        }
+    }
+    // Between two phases (e.g. allowlist closed, public sale not yet open).
+    Err(ContractError::MintingNotStarted {})
+}
+
+/// Enforces and records `phase.max_tokens_per_address` for `minter`. No-op
+/// when there is no active phase or the phase has no per-address cap.
+fn check_and_track_phase_mint(
     deps: DepsMut,
+    phase: &Option<(u8, MintPhase)>,
+    minter: &Addr,
+    amount: u32,
+) -> Result<(), ContractError> {
+    let (phase_index, phase) = match phase {
+        Some(active) => active,
+        None => return Ok(()),
+    };
+    if let Some(max) = phase.max_tokens_per_address {
+        let minted = PHASE_MINT_COUNT
+            .may_load(deps.storage, (minter, *phase_index))?
+            .unwrap_or(0);
+        if minted + amount > max {
+            return Err(ContractError::MintPhaseLimitExceeded { max });
+        }
+        PHASE_MINT_COUNT.save(deps.storage, (minter, *phase_index), &(minted + amount))?;
+    }
+    Ok(())
+}
+
+/// Folds this call's block time/height/sender into `ENTROPY_ACC`.
+fn _fold_entropy(deps: DepsMut, env: &Env, info: &MessageInfo) -> Result<(), ContractError> {
+    let acc = ENTROPY_ACC.may_load(deps.storage)?.unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(acc);
+    hasher.update(env.block.time.nanos().to_be_bytes());
+    hasher.update(env.block.height.to_be_bytes());
+    hasher.update(info.sender.as_bytes());
+    ENTROPY_ACC.save(deps.storage, &hasher.finalize().to_vec())?;
+
+    let count = ENTROPY_FOLD_COUNT
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    ENTROPY_FOLD_COUNT.save(deps.storage, &count)?;
+    ENTROPY_FOLD_SENDERS.save(
+        deps.storage,
+        count % ENTROPY_FOLD_LOG_CAPACITY,
+        &info.sender,
+    )?;
+    Ok(())
+}
+
+/// Counts distinct senders other than `committer` folded since `fold_count_at_commit`.
+/// Only the most recent `ENTROPY_FOLD_LOG_CAPACITY` folds are examined.
+fn _other_senders_since(
+    deps: Deps,
+    committer: &Addr,
+    fold_count_at_commit: u64,
+    current_fold_count: u64,
+) -> StdResult<u64> {
+    let elapsed = current_fold_count - fold_count_at_commit;
+    let scanned = elapsed.min(ENTROPY_FOLD_LOG_CAPACITY);
+    let mut others = std::collections::BTreeSet::new();
+    for i in 0..scanned {
+        let fold_count = current_fold_count - i;
+        if let Some(sender) =
+            ENTROPY_FOLD_SENDERS.may_load(deps.storage, fold_count % ENTROPY_FOLD_LOG_CAPACITY)?
+        {
+            if &sender != committer {
+                others.insert(sender);
+            }
+        }
+    }
+    Ok(others.len() as u64)
+}
+
+/// Derives a draw seed from block time/height, the committer, a nonce, and
+/// the entropy folded in since commit.
+fn _random_mint_seed(env: &Env, sender: &Addr, nonce: u64, entropy_since_commit: &[u8]) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(env.block.time.nanos().to_be_bytes());
+    hasher.update(env.block.height.to_be_bytes());
+    hasher.update(sender.as_bytes());
+    hasher.update(nonce.to_be_bytes());
+    hasher.update(entropy_since_commit);
+    let digest = hasher.finalize();
+    u64::from_be_bytes(digest[0..8].try_into().unwrap())
+}
+
+/// Removes `token_id` from the mintable pool. When `random_mint_enabled` is
+/// set this also swap-deletes it out of the `MINTABLE_TOKEN_POSITIONS` array
+/// (moving the last element into its slot) so the array stays dense for the
+/// next random draw, regardless of whether `token_id` was drawn randomly or
+/// picked explicitly.
+fn _remove_mintable_token_id(
+    deps: DepsMut,
+    config: &Config,
+    token_id: u32,
+) -> Result<(), ContractError> {
+    MINTABLE_TOKEN_IDS.remove(deps.storage, token_id);
+    let remaining = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+    let new_len = remaining - 1;
+    MINTABLE_NUM_TOKENS.save(deps.storage, &new_len)?;
+
+    if config.random_mint_enabled {
+        let position = MINTABLE_TOKEN_POSITION_OF.load(deps.storage, token_id)?;
+        if position != new_len {
+            let last_token_id = MINTABLE_TOKEN_POSITIONS.load(deps.storage, new_len)?;
+            MINTABLE_TOKEN_POSITIONS.save(deps.storage, position, &last_token_id)?;
+            MINTABLE_TOKEN_POSITION_OF.save(deps.storage, last_token_id, &position)?;
+        }
+        MINTABLE_TOKEN_POSITIONS.remove(deps.storage, new_len);
+        MINTABLE_TOKEN_POSITION_OF.remove(deps.storage, token_id);
+    }
+
+    Ok(())
+}
+
+fn _execute_batch_mint(
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     recipient: Option<Addr>,
     mut batch_token_ids: Vec<u32>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
+    let phase = active_mint_phase(deps.as_ref(), &env)?;
 
     let recipient_addr = match recipient {
         Some(some_recipient) => some_recipient,
@@ -261,15 +815,13 @@ fn _execute_batch_mint(
         };
         msgs.append(&mut vec![msg_rs]);
 
-        // Remove mintable token id from map
-        MINTABLE_TOKEN_IDS.remove(deps.storage, token_id);
-        let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
-        // Decrement mintable num tokens
-        MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+        _remove_mintable_token_id(deps.branch(), &config, token_id)?;
 
         minted_token_ids.append(&mut vec![token_id]);
         count += 1;
     }
+    check_and_track_phase_mint(deps, &phase, &info.sender, count)?;
+
     let minted_token_ids_str = format!("{:?}", minted_token_ids);
     Ok(Response::new()
         .add_attribute("sender", info.sender)
@@ -279,7 +831,8 @@ fn _execute_batch_mint(
 }
 
 fn _execute_mint(
-    deps: DepsMut,
+    mut deps: DepsMut,
+    env: Env,
     info: MessageInfo,
     recipient: Option<Addr>,
     token_id: Option<u32>,
@@ -291,6 +844,17 @@ fn _execute_mint(
         None => info.sender.clone(),
     };
 
+    // A random draw can't be settled synchronously: anyone could simulate
+    // the result against the current block before deciding whether to
+    // broadcast. Only commit here; `RevealMint` settles it once the draw
+    // can no longer be predicted by an ordinary caller.
+    if token_id.is_none() && config.random_mint_enabled {
+        return _commit_random_mint(deps, env, info, recipient_addr);
+    }
+
+    let phase = active_mint_phase(deps.as_ref(), &env)?;
+    check_and_track_phase_mint(deps.branch(), &phase, &info.sender, 1)?;
+
     let mintable_token_id = match token_id {
         Some(token_id) => {
             if token_id == 0 || token_id > config.max_tokens {
@@ -324,11 +888,7 @@ fn _execute_mint(
     };
     msgs.append(&mut vec![msg_rs]);
 
-    // Remove mintable token id from map
-    MINTABLE_TOKEN_IDS.remove(deps.storage, mintable_token_id);
-    let mintable_num_tokens = MINTABLE_NUM_TOKENS.load(deps.storage)?;
-    // Decrement mintable num tokens
-    MINTABLE_NUM_TOKENS.save(deps.storage, &(mintable_num_tokens - 1))?;
+    _remove_mintable_token_id(deps, &config, mintable_token_id)?;
 
     Ok(Response::new()
         .add_attribute("sender", info.sender)
@@ -337,6 +897,106 @@ fn _execute_mint(
         .add_messages(msgs))
 }
 
+/// Records `info.sender`'s intent to receive a randomly-drawn token id at
+/// `recipient_addr`, without drawing yet. `RevealMint` must be called in a
+/// later block to settle it.
+fn _commit_random_mint(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient_addr: Addr,
+) -> Result<Response, ContractError> {
+    if PENDING_RANDOM_MINTS.has(deps.storage, &info.sender) {
+        return Err(ContractError::PendingRandomMintExists {});
+    }
+
+    let pending = PendingRandomMint {
+        recipient: recipient_addr,
+        requested_height: env.block.height,
+        entropy_at_commit: ENTROPY_ACC.load(deps.storage)?,
+        fold_count_at_commit: ENTROPY_FOLD_COUNT.load(deps.storage)?,
+    };
+    PENDING_RANDOM_MINTS.save(deps.storage, &info.sender, &pending)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "commit_random_mint")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", pending.recipient)
+        .add_attribute("requested_height", pending.requested_height.to_string()))
+}
+
+/// Settles `committer`'s pending random mint once a later block has made its
+/// draw entropy unknowable-in-advance. Callable by anyone so a committer
+/// can't simply withhold an unfavorable reveal.
+fn _execute_reveal_mint(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    committer: Addr,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let pending = PENDING_RANDOM_MINTS
+        .may_load(deps.storage, &committer)?
+        .ok_or(ContractError::NoPendingRandomMint {})?;
+
+    if env.block.height <= pending.requested_height {
+        return Err(ContractError::RandomMintNotReadyToReveal {});
+    }
+
+    let current_fold_count = ENTROPY_FOLD_COUNT.load(deps.storage)?;
+    let other_senders = _other_senders_since(
+        deps.as_ref(),
+        &committer,
+        pending.fold_count_at_commit,
+        current_fold_count,
+    )?;
+    if other_senders < MIN_OTHER_SENDERS_SINCE_COMMIT {
+        return Err(ContractError::InsufficientRevealEntropy {
+            need: MIN_OTHER_SENDERS_SINCE_COMMIT,
+            have: other_senders,
+        });
+    }
+
+    let phase = active_mint_phase(deps.as_ref(), &env)?;
+    check_and_track_phase_mint(deps.branch(), &phase, &committer, 1)?;
+
+    let len = MINTABLE_NUM_TOKENS.load(deps.storage)?;
+    if len == 0 {
+        // Sold out: clear the commit instead of leaving `committer` stuck.
+        PENDING_RANDOM_MINTS.remove(deps.storage, &committer);
+        return Ok(Response::new()
+            .add_attribute("method", "reveal_mint")
+            .add_attribute("sender", info.sender)
+            .add_attribute("committer", committer)
+            .add_attribute("outcome", "sold_out"));
+    }
+    let nonce = RANDOM_MINT_NONCE.load(deps.storage)?;
+    RANDOM_MINT_NONCE.save(deps.storage, &(nonce + 1))?;
+
+    // Mixed with the snapshot taken at commit time.
+    let entropy_at_reveal = ENTROPY_ACC.load(deps.storage)?;
+    let mut entropy_hasher = Sha256::new();
+    entropy_hasher.update(&pending.entropy_at_commit);
+    entropy_hasher.update(&entropy_at_reveal);
+    let entropy_since_commit = entropy_hasher.finalize();
+
+    let seed = _random_mint_seed(&env, &committer, nonce, &entropy_since_commit);
+    let position = (seed % len as u64) as u32;
+    let mintable_token_id = MINTABLE_TOKEN_POSITIONS.load(deps.storage, position)?;
+
+    let msg = _create_cw721_mint(&config, &pending.recipient, mintable_token_id)?;
+    _remove_mintable_token_id(deps.branch(), &config, mintable_token_id)?;
+    PENDING_RANDOM_MINTS.remove(deps.storage, &committer);
+
+    Ok(Response::new()
+        .add_attribute("method", "reveal_mint")
+        .add_attribute("sender", info.sender)
+        .add_attribute("committer", committer)
+        .add_attribute("recipient", pending.recipient)
+        .add_attribute("token_id", mintable_token_id.to_string())
+        .add_message(msg))
+}
+
 fn _create_cw721_mint<'a>(
     config: &'a Config,
     recipient_addr: &'a Addr,
@@ -438,6 +1098,74 @@ fn _execute_batch_transfer_nft(
         .add_messages(msgs))
 }
 
+fn _execute_send_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract: Addr,
+    token_id: u32,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let send_msg = _create_cw721_send(&config, &contract, token_id, msg)?;
+
+    Ok(Response::new()
+        .add_attribute("sender", info.sender)
+        .add_attribute("contract", contract)
+        .add_attribute("token_id", token_id.to_string())
+        .add_message(send_msg))
+}
+
+fn _create_cw721_send<'a>(
+    config: &'a Config,
+    contract_addr: &'a Addr,
+    token_id: u32,
+    msg: Binary,
+) -> Result<CosmosMsg, ContractError> {
+    let send_msg: Cw721ExecuteMsg<Empty> = Cw721ExecuteMsg::SendNft {
+        contract: contract_addr.to_string(),
+        token_id: token_id.to_string(),
+        msg,
+    };
+    let wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: config.cw721_address.as_ref().unwrap().to_string(),
+        msg: to_binary(&send_msg)?,
+        funds: vec![],
+    });
+    Ok(wasm_msg)
+}
+
+fn _execute_batch_send_nft(
+    deps: DepsMut,
+    info: MessageInfo,
+    contract: Addr,
+    mut batch_token_ids: Vec<u32>,
+    msg: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    let mut msgs: Vec<CosmosMsg<Empty>> = vec![];
+    let mut count: u32 = 0;
+    let mut sent_token_ids: Vec<u32> = vec![];
+    while let Some(token_id) = batch_token_ids.pop() {
+        if count >= config.max_tokens_per_batch_transfer {
+            break;
+        }
+
+        let send_msg = _create_cw721_send(&config, &contract, token_id, msg.clone())?;
+        msgs.push(send_msg);
+
+        sent_token_ids.append(&mut vec![token_id]);
+        count += 1;
+    }
+    let sent_token_ids_str = format!("{:?}", sent_token_ids);
+    Ok(Response::new()
+        .add_attribute("sender", info.sender)
+        .add_attribute("contract", contract)
+        .add_attribute("token_id", sent_token_ids_str)
+        .add_messages(msgs))
+}
+
 /// NOTE: default behaviour here is to round down
 /// EIP2981 specifies that the rounding behaviour is at the discretion of the implementer
 pub fn query_royalties_info(deps: Deps, sale_price: Uint128) -> StdResult<RoyaltiesInfoResponse> {
@@ -460,22 +1188,768 @@ pub fn query_royalties_info(deps: Deps, sale_price: Uint128) -> StdResult<Royalt
     })
 }
 
-// Reply callback triggered from cw721 contract instantiation
+// Reply callback triggered from cw721/cw1155 contract instantiation
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
     let mut config: Config = CONFIG.load(deps.storage)?;
-    if msg.id != INSTANTIATE_CW721_REPLY_ID {
-        return Err(ContractError::InvalidReplyID {});
-    }
-
-    let reply = parse_reply_instantiate_data(msg);
-    match reply {
-        Ok(res) => {
+    match msg.id {
+        INSTANTIATE_CW721_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)
+                .map_err(|_| ContractError::InstantiateCW721Error {})?;
             config.cw721_address = Addr::unchecked(res.contract_address.clone()).into();
             CONFIG.save(deps.storage, &config)?;
             CW721_ADDRESS.save(deps.storage, &Addr::unchecked(res.contract_address))?;
             Ok(Response::default().add_attribute("action", "instantiate_cw721_reply"))
         }
-        Err(_) => Err(ContractError::InstantiateCW721Error {}),
+        INSTANTIATE_CW1155_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)
+                .map_err(|_| ContractError::InstantiateCW1155Error {})?;
+            config.cw1155_address = Addr::unchecked(res.contract_address).into();
+            CONFIG.save(deps.storage, &config)?;
+            Ok(Response::default().add_attribute("action", "instantiate_cw1155_reply"))
+        }
+        _ => Err(ContractError::InvalidReplyID {}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msg::MintPhaseMsg;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::Timestamp;
+
+    fn base_instantiate_msg(num_tokens: u32) -> InstantiateMsg {
+        InstantiateMsg {
+            name: "Test Collection".to_string(),
+            symbol: "TEST".to_string(),
+            cw721_code_id: 1,
+            num_tokens,
+            max_tokens_per_batch_mint: 10,
+            max_tokens_per_batch_transfer: 10,
+            base_token_uri: "ipfs://abc".to_string(),
+            royalty_percentage: None,
+            royalty_payment_address: None,
+            cw1155_code_id: None,
+            editions: None,
+            mint_phases: None,
+            random_mint_enabled: false,
+        }
+    }
+
+    /// Instantiates the contract and fakes the cw721 reply so `_execute_mint`
+    /// can build mint messages without driving an actual sub-message reply.
+    fn setup(mut deps: DepsMut, env: Env, info: MessageInfo, msg: InstantiateMsg) {
+        instantiate(deps.branch(), env, info, msg).unwrap();
+        let mut config = CONFIG.load(deps.storage).unwrap();
+        config.cw721_address = Some(Addr::unchecked("cw721_contract"));
+        CONFIG.save(deps.storage, &config).unwrap();
+    }
+
+    #[test]
+    fn instantiate_rejects_overlapping_phases() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(10);
+        msg.mint_phases = Some(vec![
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(100),
+                end_time: Timestamp::from_seconds(200),
+                max_tokens_per_address: None,
+            },
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(150),
+                end_time: Timestamp::from_seconds(250),
+                max_tokens_per_address: None,
+            },
+        ]);
+
+        let err = instantiate(deps.as_mut(), env, info, msg).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidMintPhases {}));
+    }
+
+    #[test]
+    fn instantiate_accepts_adjacent_phases() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(10);
+        msg.mint_phases = Some(vec![
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(100),
+                end_time: Timestamp::from_seconds(200),
+                max_tokens_per_address: None,
+            },
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(200),
+                end_time: Timestamp::from_seconds(300),
+                max_tokens_per_address: None,
+            },
+        ]);
+
+        instantiate(deps.as_mut(), env, info, msg).unwrap();
+    }
+
+    #[test]
+    fn mint_across_phase_boundary() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(10);
+        msg.mint_phases = Some(vec![
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(100),
+                end_time: Timestamp::from_seconds(200),
+                max_tokens_per_address: None,
+            },
+            MintPhaseMsg {
+                start_time: Timestamp::from_seconds(250),
+                end_time: Timestamp::from_seconds(300),
+                max_tokens_per_address: None,
+            },
+        ]);
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+
+        // Before the first phase opens.
+        env.block.time = Timestamp::from_seconds(50);
+        let err =
+            execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(1)).unwrap_err();
+        assert!(matches!(err, ContractError::MintingNotStarted {}));
+
+        // Inside the first phase.
+        env.block.time = Timestamp::from_seconds(150);
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(1)).unwrap();
+
+        // In the gap between the two phases.
+        env.block.time = Timestamp::from_seconds(225);
+        let err =
+            execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(2)).unwrap_err();
+        assert!(matches!(err, ContractError::MintingNotStarted {}));
+
+        // Inside the second phase.
+        env.block.time = Timestamp::from_seconds(275);
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(2)).unwrap();
+
+        // After the last phase closes.
+        env.block.time = Timestamp::from_seconds(400);
+        let err = execute_mint_sender(deps.as_mut(), env, info, Some(3)).unwrap_err();
+        assert!(matches!(err, ContractError::MintingEnded {}));
+    }
+
+    #[test]
+    fn mint_phase_enforces_max_tokens_per_address() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info("minter", &[]);
+
+        let mut msg = base_instantiate_msg(10);
+        msg.mint_phases = Some(vec![MintPhaseMsg {
+            start_time: Timestamp::from_seconds(100),
+            end_time: Timestamp::from_seconds(200),
+            max_tokens_per_address: Some(2),
+        }]);
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+        env.block.time = Timestamp::from_seconds(150);
+
+        // Minting up to the cap succeeds.
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(1)).unwrap();
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(2)).unwrap();
+
+        // One more over the cap is rejected...
+        let err =
+            execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), Some(3)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::MintPhaseLimitExceeded { max: 2 }
+        ));
+
+        // ...and a different address in the same phase is unaffected.
+        let other = mock_info("other", &[]);
+        execute_mint_sender(deps.as_mut(), env, other, Some(3)).unwrap();
+    }
+
+    fn token_id_attr(res: &Response) -> u32 {
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == "token_id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    }
+
+    /// Commits a random mint for each of `names` to fold their entropy in.
+    fn fold_bystanders(mut deps: DepsMut, env: &Env, names: &[&str]) {
+        for name in names {
+            execute(
+                deps.branch(),
+                env.clone(),
+                mock_info(name, &[]),
+                ExecuteMsg::Mint { token_id: None },
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    fn random_mint_reveal_requires_a_later_block() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info("minter", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+
+        // Same block as the commit: the draw's entropy doesn't exist yet.
+        let err = execute_reveal_mint(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            "minter".to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::RandomMintNotReadyToReveal {}));
+
+        // A later block, with enough other activity folded in, can settle it.
+        env.block.height += 1;
+        fold_bystanders(deps.as_mut(), &env, &["bystander0", "bystander1"]);
+        execute_reveal_mint(deps.as_mut(), env, info, "minter".to_string()).unwrap();
+    }
+
+    #[test]
+    fn random_mint_second_commit_blocked_until_revealed() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info("minter", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+        let err = execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap_err();
+        assert!(matches!(err, ContractError::PendingRandomMintExists {}));
+
+        env.block.height += 1;
+        fold_bystanders(deps.as_mut(), &env, &["bystander0", "bystander1"]);
+        execute_reveal_mint(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            "minter".to_string(),
+        )
+        .unwrap();
+
+        // The slot is free again once revealed.
+        execute_mint_sender(deps.as_mut(), env, info, None).unwrap();
+    }
+
+    #[test]
+    fn random_mint_reveal_is_permissionless() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let committer = mock_info("minter", &[]);
+        let relayer = mock_info("anyone", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), committer.clone(), msg);
+
+        execute_mint_sender(deps.as_mut(), env.clone(), committer, None).unwrap();
+        env.block.height += 1;
+        fold_bystanders(deps.as_mut(), &env, &["bystander0"]);
+
+        // A third party can force the reveal; the committer can't grind by
+        // simply refusing to call it themselves. The relayer's own call
+        // folds one more distinct sender, satisfying the entropy gate.
+        execute(
+            deps.as_mut(),
+            env,
+            relayer,
+            ExecuteMsg::RevealMint {
+                committer: "minter".to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn random_mint_exhausts_pool_without_duplicates() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+
+        let mut msg = base_instantiate_msg(4);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), mock_info("owner", &[]), msg);
+
+        // Commit all four first; each committer's own commit folds entropy
+        // the others can later rely on at reveal time.
+        let committers: Vec<String> = (0..4).map(|i| format!("minter{}", i)).collect();
+        for committer in &committers {
+            execute(
+                deps.as_mut(),
+                env.clone(),
+                mock_info(committer, &[]),
+                ExecuteMsg::Mint { token_id: None },
+            )
+            .unwrap();
+        }
+
+        env.block.height += 1;
+        let mut drawn: Vec<u32> = vec![];
+        for committer in &committers {
+            let info = mock_info(committer, &[]);
+            let res = execute(
+                deps.as_mut(),
+                env.clone(),
+                info,
+                ExecuteMsg::RevealMint {
+                    committer: committer.clone(),
+                },
+            )
+            .unwrap();
+            drawn.push(token_id_attr(&res));
+        }
+
+        drawn.sort_unstable();
+        assert_eq!(drawn, vec![1, 2, 3, 4]);
+
+        // The pool is empty: reveal settles gracefully (clearing the commit)
+        // instead of leaving `latecomer` stuck behind `PendingRandomMintExists`.
+        let info = mock_info("latecomer", &[]);
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+        fold_bystanders(deps.as_mut(), &env, &["bystander0", "bystander1"]);
+        env.block.height += 1;
+        let res = execute_reveal_mint(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            "latecomer".to_string(),
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes
+                .iter()
+                .find(|a| a.key == "outcome")
+                .unwrap()
+                .value,
+            "sold_out"
+        );
+        assert!(!PENDING_RANDOM_MINTS.has(deps.as_ref().storage, &Addr::unchecked("latecomer")));
+
+        // Cleared, so latecomer isn't blocked from committing again.
+        execute_mint_sender(deps.as_mut(), env, info, None).unwrap();
+    }
+
+    #[test]
+    fn random_mint_reveal_rejects_insufficient_entropy() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let info = mock_info("minter", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+        env.block.height += 1;
+
+        // Nobody else interacted with the contract since the commit: the
+        // committer's own reveal call alone isn't enough other-sender entropy.
+        let err = execute(
+            deps.as_mut(),
+            env,
+            info,
+            ExecuteMsg::RevealMint {
+                committer: "minter".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientRevealEntropy { .. }
+        ));
+    }
+
+    #[test]
+    fn cancel_random_mint_frees_the_pending_slot() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let info = mock_info("minter", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), info.clone(), msg);
+
+        execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap();
+        let err = execute_mint_sender(deps.as_mut(), env.clone(), info.clone(), None).unwrap_err();
+        assert!(matches!(err, ContractError::PendingRandomMintExists {}));
+
+        execute_cancel_random_mint(deps.as_mut(), info.clone()).unwrap();
+        assert!(!PENDING_RANDOM_MINTS.has(deps.as_ref().storage, &info.sender));
+
+        // Free to commit again.
+        execute_mint_sender(deps.as_mut(), env, info, None).unwrap();
+    }
+
+    #[test]
+    fn random_mint_mixes_with_explicit_draws() {
+        let mut deps = mock_dependencies();
+        let mut env = mock_env();
+        let owner = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.random_mint_enabled = true;
+        setup(deps.as_mut(), env.clone(), owner.clone(), msg);
+
+        // Explicitly mint token 3 first.
+        execute_mint_sender(deps.as_mut(), env.clone(), owner.clone(), Some(3)).unwrap();
+
+        // Commit and reveal a random draw from the remaining {1, 2, 4, 5}.
+        execute_mint_sender(deps.as_mut(), env.clone(), owner.clone(), None).unwrap();
+        env.block.height += 1;
+        fold_bystanders(deps.as_mut(), &env, &["bystander0", "bystander1"]);
+        let res =
+            execute_reveal_mint(deps.as_mut(), env.clone(), owner, "owner".to_string()).unwrap();
+        let random_token_id = token_id_attr(&res);
+
+        assert_ne!(random_token_id, 3);
+        assert!((1..=5).contains(&random_token_id));
+        assert!(!MINTABLE_TOKEN_IDS.has(deps.as_ref().storage, 3));
+        assert!(!MINTABLE_TOKEN_IDS.has(deps.as_ref().storage, random_token_id));
+        assert_eq!(MINTABLE_NUM_TOKENS.load(deps.as_ref().storage).unwrap(), 3);
+    }
+
+    #[test]
+    fn update_config_rejects_non_owner() {
+        let mut deps = mock_dependencies();
+        setup(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            base_instantiate_msg(10),
+        );
+
+        let err = execute_update_config(deps.as_mut(), mock_info("not_owner", &[]), Some(5), None)
+            .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn update_royalties_rejects_non_owner() {
+        let mut deps = mock_dependencies();
+        setup(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("owner", &[]),
+            base_instantiate_msg(10),
+        );
+
+        let err =
+            execute_update_royalties(deps.as_mut(), mock_info("not_owner", &[]), Some(5), None)
+                .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    #[test]
+    fn update_royalties_rejects_out_of_bounds_percentage() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup(deps.as_mut(), env, owner.clone(), base_instantiate_msg(10));
+
+        let err =
+            execute_update_royalties(deps.as_mut(), owner, Some(MAX_ROYALTY_PERCENTAGE + 1), None)
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidRoyaltyPercentage { min: 0, max } if max == MAX_ROYALTY_PERCENTAGE
+        ));
+    }
+
+    #[test]
+    fn transfer_ownership_rejects_non_owner() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        setup(
+            deps.as_mut(),
+            env,
+            mock_info("owner", &[]),
+            base_instantiate_msg(10),
+        );
+
+        let err = execute_transfer_ownership(
+            deps.as_mut(),
+            mock_info("not_owner", &[]),
+            "new_owner".to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+    }
+
+    fn setup_with_editions(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        editions: Vec<(u32, Uint128)>,
+    ) {
+        let mut msg = base_instantiate_msg(5);
+        msg.cw1155_code_id = Some(2);
+        msg.editions = Some(editions);
+        setup(deps.branch(), env, info, msg);
+        let mut config = CONFIG.load(deps.storage).unwrap();
+        config.cw1155_address = Some(Addr::unchecked("cw1155_contract"));
+        CONFIG.save(deps.storage, &config).unwrap();
+    }
+
+    #[test]
+    fn mint_edition_decrements_remaining_supply() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup_with_editions(
+            deps.as_mut(),
+            env,
+            owner.clone(),
+            vec![(1, Uint128::new(10))],
+        );
+
+        _execute_mint_edition(
+            deps.as_mut(),
+            owner,
+            1,
+            Uint128::new(4),
+            "recipient".to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            EDITION_REMAINING_SUPPLY
+                .load(deps.as_ref().storage, 1)
+                .unwrap(),
+            Uint128::new(6)
+        );
+    }
+
+    #[test]
+    fn mint_edition_rejects_over_mint() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup_with_editions(
+            deps.as_mut(),
+            env,
+            owner.clone(),
+            vec![(1, Uint128::new(3))],
+        );
+
+        let err = _execute_mint_edition(
+            deps.as_mut(),
+            owner,
+            1,
+            Uint128::new(4),
+            "recipient".to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::EditionSoldOut { token_id: 1 }));
+    }
+
+    #[test]
+    fn mint_edition_rejects_unconfigured_token_id() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup_with_editions(
+            deps.as_mut(),
+            env,
+            owner.clone(),
+            vec![(1, Uint128::new(3))],
+        );
+
+        let err = _execute_mint_edition(
+            deps.as_mut(),
+            owner,
+            99,
+            Uint128::new(1),
+            "recipient".to_string(),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidEditionTokenId { token_id: 99 }
+        ));
+    }
+
+    #[test]
+    fn batch_mint_edition_respects_max_tokens_per_batch_mint() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.cw1155_code_id = Some(2);
+        msg.max_tokens_per_batch_mint = 2;
+        msg.editions = Some(vec![
+            (1, Uint128::new(10)),
+            (2, Uint128::new(10)),
+            (3, Uint128::new(10)),
+        ]);
+        setup(deps.as_mut(), env, owner.clone(), msg);
+        let mut config = CONFIG.load(deps.as_ref().storage).unwrap();
+        config.cw1155_address = Some(Addr::unchecked("cw1155_contract"));
+        CONFIG.save(deps.as_mut().storage, &config).unwrap();
+
+        let mints = vec![
+            EditionMint {
+                token_id: 1,
+                amount: Uint128::new(1),
+                recipient: "recipient1".to_string(),
+            },
+            EditionMint {
+                token_id: 2,
+                amount: Uint128::new(1),
+                recipient: "recipient2".to_string(),
+            },
+            EditionMint {
+                token_id: 3,
+                amount: Uint128::new(1),
+                recipient: "recipient3".to_string(),
+            },
+        ];
+        let res = _execute_batch_mint_edition(deps.as_mut(), owner, mints).unwrap();
+
+        // Only the first two mints (the batch cap) actually happened.
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            EDITION_REMAINING_SUPPLY
+                .load(deps.as_ref().storage, 3)
+                .unwrap(),
+            Uint128::new(10)
+        );
+    }
+
+    #[test]
+    fn mintable_tokens_query_paginates_in_ascending_order() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        setup(
+            deps.as_mut(),
+            env,
+            mock_info("owner", &[]),
+            base_instantiate_msg(5),
+        );
+
+        let page1 = query_mintable_tokens(deps.as_ref(), None, Some(2)).unwrap();
+        assert_eq!(page1.tokens, vec![1, 2]);
+        assert_eq!(page1.count, 5);
+
+        let page2 =
+            query_mintable_tokens(deps.as_ref(), page1.tokens.last().copied(), Some(2)).unwrap();
+        assert_eq!(page2.tokens, vec![3, 4]);
+        assert_eq!(page2.count, 5);
+    }
+
+    #[test]
+    fn mintable_tokens_query_excludes_minted_tokens() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup(
+            deps.as_mut(),
+            env.clone(),
+            owner.clone(),
+            base_instantiate_msg(5),
+        );
+
+        execute_mint_sender(deps.as_mut(), env, owner, Some(2)).unwrap();
+
+        let page = query_mintable_tokens(deps.as_ref(), None, None).unwrap();
+        assert_eq!(page.tokens, vec![1, 3, 4, 5]);
+        assert_eq!(page.count, 4);
+    }
+
+    #[test]
+    fn mintable_tokens_query_caps_limit_at_max() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        setup(
+            deps.as_mut(),
+            env,
+            mock_info("owner", &[]),
+            base_instantiate_msg(MAX_MINTABLE_TOKENS_LIMIT + 10),
+        );
+
+        let page = query_mintable_tokens(deps.as_ref(), None, Some(MAX_MINTABLE_TOKENS_LIMIT + 10))
+            .unwrap();
+        assert_eq!(page.tokens.len(), MAX_MINTABLE_TOKENS_LIMIT as usize);
+    }
+
+    #[test]
+    fn send_nft_builds_cw721_receive_hook_message() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+        setup(deps.as_mut(), env, owner.clone(), base_instantiate_msg(5));
+
+        let hook_msg = to_binary(&"deposit").unwrap();
+        let res = execute_send_nft(
+            deps.as_mut(),
+            owner,
+            "escrow".to_string(),
+            3,
+            hook_msg.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        match &res.messages[0].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr, msg, ..
+            }) => {
+                assert_eq!(contract_addr, "cw721_contract");
+                match cosmwasm_std::from_binary(msg).unwrap() {
+                    Cw721ExecuteMsg::<Empty>::SendNft {
+                        contract,
+                        token_id,
+                        msg,
+                    } => {
+                        assert_eq!(contract, "escrow");
+                        assert_eq!(token_id, "3");
+                        assert_eq!(msg, hook_msg);
+                    }
+                    other => panic!("expected SendNft, got {:?}", other),
+                }
+            }
+            other => panic!("expected a wasm execute message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn batch_send_nft_respects_max_tokens_per_batch_transfer() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+        let owner = mock_info("owner", &[]);
+
+        let mut msg = base_instantiate_msg(5);
+        msg.max_tokens_per_batch_transfer = 2;
+        setup(deps.as_mut(), env, owner.clone(), msg);
+
+        let res = execute_batch_send_nft(
+            deps.as_mut(),
+            owner,
+            "escrow".to_string(),
+            vec![1, 2, 3],
+            to_binary(&"deposit").unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(res.messages.len(), 2);
     }
 }
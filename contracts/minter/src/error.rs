@@ -0,0 +1,84 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+use url::ParseError;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    ParseError(#[from] ParseError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("InvalidNumTokens: min {min} max {max}")]
+    InvalidNumTokens { min: u32, max: u32 },
+
+    #[error("InvalidMaxTokensPerBatchMint: min {min} max {max}")]
+    InvalidMaxTokensPerBatchMint { min: u32, max: u32 },
+
+    #[error("InvalidMaxTokensPerBatchTransfer: min {min} max {max}")]
+    InvalidMaxTokensPerBatchTransfer { min: u32, max: u32 },
+
+    #[error("InvalidBaseTokenURI")]
+    InvalidBaseTokenURI {},
+
+    #[error("InvalidTokenId")]
+    InvalidTokenId {},
+
+    #[error("TokenIdAlreadySold: token_id {token_id}")]
+    TokenIdAlreadySold { token_id: u32 },
+
+    #[error("SoldOut")]
+    SoldOut {},
+
+    #[error("InvalidReplyID")]
+    InvalidReplyID {},
+
+    #[error("InstantiateCW721Error")]
+    InstantiateCW721Error {},
+
+    #[error("InstantiateCW1155Error")]
+    InstantiateCW1155Error {},
+
+    #[error("MissingCw1155CodeId: editions were configured but no cw1155_code_id was provided")]
+    MissingCw1155CodeId {},
+
+    #[error("InvalidEditionTokenId: token_id {token_id}")]
+    InvalidEditionTokenId { token_id: u32 },
+
+    #[error("EditionSoldOut: token_id {token_id}")]
+    EditionSoldOut { token_id: u32 },
+
+    #[error("InvalidEditionAmount")]
+    InvalidEditionAmount {},
+
+    #[error("MintingNotStarted")]
+    MintingNotStarted {},
+
+    #[error("MintingEnded")]
+    MintingEnded {},
+
+    #[error("InvalidMintPhases")]
+    InvalidMintPhases {},
+
+    #[error("InvalidRoyaltyPercentage: min {min} max {max}")]
+    InvalidRoyaltyPercentage { min: u64, max: u64 },
+
+    #[error("MintPhaseLimitExceeded: max {max} tokens per address in this phase")]
+    MintPhaseLimitExceeded { max: u32 },
+
+    #[error("PendingRandomMintExists: call RevealMint before committing another random mint")]
+    PendingRandomMintExists {},
+
+    #[error("NoPendingRandomMint")]
+    NoPendingRandomMint {},
+
+    #[error("RandomMintNotReadyToReveal: wait for the next block before calling RevealMint")]
+    RandomMintNotReadyToReveal {},
+
+    #[error("InsufficientRevealEntropy: need {need} other senders since commit, have {have}")]
+    InsufficientRevealEntropy { need: u64, have: u64 },
+}
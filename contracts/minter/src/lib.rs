@@ -0,0 +1,35 @@
+pub mod contract;
+pub mod error;
+pub mod msg;
+pub mod state;
+
+pub use schemars::JsonSchema;
+pub use serde::{Deserialize, Serialize};
+
+/// NFT metadata extension stored on-chain for every token minted through this
+/// contract. `royalty_percentage`/`royalty_payment_address` back the
+/// EIP-2981-style `RoyaltyInfo` query; the rest mirrors the common
+/// cw721 on-chain metadata fields.
+pub type Extension = Option<Metadata>;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct Trait {
+    pub display_type: Option<String>,
+    pub trait_type: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct Metadata {
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub external_url: Option<String>,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub attributes: Option<Vec<Trait>>,
+    pub background_color: Option<String>,
+    pub animation_url: Option<String>,
+    pub youtube_url: Option<String>,
+    pub royalty_percentage: Option<u64>,
+    pub royalty_payment_address: Option<String>,
+}
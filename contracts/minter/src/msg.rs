@@ -0,0 +1,275 @@
+use cosmwasm_std::{Addr, Binary, Timestamp, Uint128};
+use cw721_base::QueryMsg as Cw721QueryMsg;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Metadata;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintPhaseMsg {
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub max_tokens_per_address: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub cw721_code_id: u64,
+    pub num_tokens: u32,
+    pub max_tokens_per_batch_mint: u32,
+    pub max_tokens_per_batch_transfer: u32,
+    pub base_token_uri: String,
+    pub royalty_percentage: Option<u64>,
+    pub royalty_payment_address: Option<String>,
+    /// Code id of the cw1155-base contract to instantiate for edition
+    /// minting. Leave unset to run this minter in cw721-only mode.
+    pub cw1155_code_id: Option<u64>,
+    /// `(token_id, max_supply)` pairs seeding the edition pool. Each
+    /// `token_id` here is independent of the cw721 `num_tokens` pool and is
+    /// minted/tracked through the cw1155 path instead.
+    pub editions: Option<Vec<(u32, Uint128)>>,
+    /// Ordered mint windows (e.g. allowlist then public sale). Leave unset
+    /// to keep minting unconditionally open, as before.
+    pub mint_phases: Option<Vec<MintPhaseMsg>>,
+    /// Opt into uniform-random token id assignment for anonymous
+    /// `Mint`/`MintTo` calls instead of always handing out the
+    /// lexicographically first remaining id.
+    #[serde(default)]
+    pub random_mint_enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecuteMsg {
+    /// `token_id: None` draws a token id instead of choosing one explicitly.
+    /// When `random_mint_enabled` is set this only commits the draw — call
+    /// `RevealMint` afterwards to complete it.
+    Mint {
+        token_id: Option<u32>,
+    },
+    BatchMint {
+        token_ids: Vec<u32>,
+    },
+    MintTo {
+        token_id: Option<u32>,
+        recipient: String,
+    },
+    /// Draws and mints the token id committed by an earlier `Mint`/`MintTo`
+    /// call with no `token_id`. Callable by anyone, so a committer can't
+    /// grind by declining to reveal an unfavorable draw.
+    RevealMint {
+        committer: String,
+    },
+    /// Withdraws the sender's own pending random mint without drawing.
+    CancelRandomMint {},
+    TransferNft {
+        recipient: String,
+        token_id: u32,
+    },
+    BatchTransferNft {
+        recipient: String,
+        token_ids: Vec<u32>,
+    },
+    /// Like `TransferNft`, but fires the receiving contract's
+    /// `Cw721ReceiveMsg` hook instead of landing silently, so the token can
+    /// be deposited into an escrow, auction house, or staking contract.
+    SendNft {
+        contract: String,
+        token_id: u32,
+        msg: Binary,
+    },
+    BatchSendNft {
+        contract: String,
+        token_ids: Vec<u32>,
+        msg: Binary,
+    },
+    MintEdition {
+        token_id: u32,
+        amount: Uint128,
+        recipient: String,
+    },
+    BatchMintEdition {
+        mints: Vec<EditionMint>,
+    },
+    UpdateConfig {
+        max_tokens_per_batch_mint: Option<u32>,
+        max_tokens_per_batch_transfer: Option<u32>,
+    },
+    UpdateRoyalties {
+        royalty_percentage: Option<u64>,
+        royalty_payment_address: Option<String>,
+    },
+    TransferOwnership {
+        new_owner: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct EditionMint {
+    pub token_id: u32,
+    pub amount: Uint128,
+    pub recipient: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MinterQueryMsg {
+    GetConfig {},
+    RoyaltyInfo {
+        sale_price: Uint128,
+    },
+    /// Remaining unminted token ids, paginated by `start_after`/`limit`
+    /// (mirrors cw721-base's `TokensByOwner` pagination).
+    MintableTokens {
+        start_after: Option<u32>,
+        limit: Option<u32>,
+    },
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    Approval {
+        token_id: String,
+        spender: String,
+        include_expired: Option<bool>,
+    },
+    Approvals {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    Operator {
+        owner: String,
+        operator: String,
+        include_expired: Option<bool>,
+    },
+    AllOperators {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    NumTokens {},
+    ContractInfo {},
+    NftInfo {
+        token_id: String,
+    },
+    AllNftInfo {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Minter {},
+}
+
+impl From<MinterQueryMsg> for Cw721QueryMsg<cosmwasm_std::Empty> {
+    fn from(msg: MinterQueryMsg) -> Cw721QueryMsg<cosmwasm_std::Empty> {
+        match msg {
+            MinterQueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            } => Cw721QueryMsg::OwnerOf {
+                token_id,
+                include_expired,
+            },
+            MinterQueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            } => Cw721QueryMsg::Approval {
+                token_id,
+                spender,
+                include_expired,
+            },
+            MinterQueryMsg::Approvals {
+                token_id,
+                include_expired,
+            } => Cw721QueryMsg::Approvals {
+                token_id,
+                include_expired,
+            },
+            MinterQueryMsg::Operator {
+                owner,
+                operator,
+                include_expired,
+            } => Cw721QueryMsg::Operator {
+                owner,
+                operator,
+                include_expired,
+            },
+            MinterQueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::AllOperators {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            },
+            MinterQueryMsg::NumTokens {} => Cw721QueryMsg::NumTokens {},
+            MinterQueryMsg::ContractInfo {} => Cw721QueryMsg::ContractInfo {},
+            MinterQueryMsg::NftInfo { token_id } => Cw721QueryMsg::NftInfo { token_id },
+            MinterQueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            } => Cw721QueryMsg::AllNftInfo {
+                token_id,
+                include_expired,
+            },
+            MinterQueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            } => Cw721QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            },
+            MinterQueryMsg::AllTokens { start_after, limit } => {
+                Cw721QueryMsg::AllTokens { start_after, limit }
+            }
+            MinterQueryMsg::Minter {} => Cw721QueryMsg::Minter {},
+            MinterQueryMsg::GetConfig {}
+            | MinterQueryMsg::RoyaltyInfo { .. }
+            | MinterQueryMsg::MintableTokens { .. } => {
+                unreachable!("handled directly in contract::query before falling back to cw721")
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConfigResponse {
+    pub owner: Addr,
+    pub cw721_code_id: u64,
+    pub cw721_address: Option<Addr>,
+    pub cw1155_code_id: Option<u64>,
+    pub cw1155_address: Option<Addr>,
+    pub name: String,
+    pub symbol: String,
+    pub base_token_uri: String,
+    pub max_tokens: u32,
+    pub max_tokens_per_mint: u32,
+    pub max_tokens_per_batch_transfer: u32,
+    pub extension: Option<Metadata>,
+    /// Whether anonymous `Mint`/`MintTo` calls draw a random token id instead
+    /// of always handing out the lexicographically first remaining one.
+    pub random_mint_enabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoyaltiesInfoResponse {
+    pub royalty_address: String,
+    pub royalty_amount: Uint128,
+}
@@ -0,0 +1,95 @@
+use cosmwasm_std::{Addr, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Config {
+    pub owner: Addr,
+    pub cw721_code_id: u64,
+    pub cw721_address: Option<Addr>,
+    /// Code id of the cw1155-base contract instantiated for `MintEdition` /
+    /// `BatchMintEdition`. `None` until an edition collection is configured.
+    pub cw1155_code_id: Option<u64>,
+    pub cw1155_address: Option<Addr>,
+    pub name: String,
+    pub symbol: String,
+    pub base_token_uri: String,
+    pub max_tokens: u32,
+    pub max_tokens_per_batch_mint: u32,
+    pub max_tokens_per_batch_transfer: u32,
+    pub royalty_percentage: Option<u64>,
+    pub royalty_payment_address: Option<String>,
+    /// When true, anonymous `Mint`/`MintTo` calls (no explicit `token_id`)
+    /// draw a uniformly random id from the live pool instead of always
+    /// taking the lexicographically first one.
+    pub random_mint_enabled: bool,
+}
+
+pub const CONFIG: Item<Config> = Item::new("config");
+pub const CW721_ADDRESS: Item<Addr> = Item::new("cw721_address");
+pub const MINTABLE_NUM_TOKENS: Item<u32> = Item::new("mintable_num_tokens");
+pub const MINTABLE_TOKEN_IDS: Map<u32, bool> = Map::new("mintable_token_ids");
+
+/// Remaining mintable supply for each edition `token_id`, seeded at
+/// instantiate from `InstantiateMsg::editions` and decremented by
+/// `MintEdition`/`BatchMintEdition`. An id absent from this map is not an
+/// edition and must go through the cw721 one-of-one path instead.
+pub const EDITION_REMAINING_SUPPLY: Map<u32, Uint128> = Map::new("edition_remaining_supply");
+
+/// A single ordered mint window, e.g. an allowlist phase followed by a
+/// public-sale phase. `max_tokens_per_address` is enforced only within that
+/// phase's own window, tracked by `PHASE_MINT_COUNT`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct MintPhase {
+    pub start_time: Timestamp,
+    pub end_time: Timestamp,
+    pub max_tokens_per_address: Option<u32>,
+}
+
+/// Ordered list of mint phases. Empty means minting is unconditionally open,
+/// matching the contract's original behavior.
+pub const MINT_PHASES: Item<Vec<MintPhase>> = Item::new("mint_phases");
+
+/// Number of tokens `(address, phase_index)` has minted so far, used to
+/// enforce each phase's `max_tokens_per_address`.
+pub const PHASE_MINT_COUNT: Map<(&Addr, u8), u32> = Map::new("phase_mint_count");
+
+/// Dynamic array of still-mintable token ids, `position -> token_id`, used
+/// only when `Config::random_mint_enabled` is set. Its length is tracked by
+/// `MINTABLE_NUM_TOKENS` and it is kept dense via swap-delete, so a draw is
+/// `array[seed % len]` followed by moving `array[len-1]` into the drawn slot.
+pub const MINTABLE_TOKEN_POSITIONS: Map<u32, u32> = Map::new("mintable_token_positions");
+/// Reverse index `token_id -> position`, so removing an explicitly chosen
+/// `token_id` can locate and swap-delete its slot in `MINTABLE_TOKEN_POSITIONS`
+/// in O(1) as well.
+pub const MINTABLE_TOKEN_POSITION_OF: Map<u32, u32> = Map::new("mintable_token_position_of");
+/// Monotonically-incrementing nonce folded into the random draw seed so that
+/// two draws in the same block/sender never collide.
+pub const RANDOM_MINT_NONCE: Item<u64> = Item::new("random_mint_nonce");
+
+/// A committed-but-unrevealed random mint, keyed by the committer.
+/// `RevealMint` can only draw once `requested_height` is in the past.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingRandomMint {
+    pub recipient: Addr,
+    pub requested_height: u64,
+    pub entropy_at_commit: Vec<u8>,
+    /// `ENTROPY_FOLD_COUNT` at commit time.
+    pub fold_count_at_commit: u64,
+}
+
+/// One pending commit per committer at a time; a second `Mint`/`MintTo` call
+/// must wait for the first to be revealed or cancelled with `CancelRandomMint`.
+pub const PENDING_RANDOM_MINTS: Map<&Addr, PendingRandomMint> = Map::new("pending_random_mints");
+
+/// Running hash folded with the block time/height/sender of every `execute`
+/// call (see `contract::execute`), mixed into the random mint seed at reveal.
+pub const ENTROPY_ACC: Item<Vec<u8>> = Item::new("entropy_acc");
+
+/// Number of times `ENTROPY_ACC` has been folded.
+pub const ENTROPY_FOLD_COUNT: Item<u64> = Item::new("entropy_fold_count");
+
+/// Ring buffer of the sender behind each of the last
+/// `ENTROPY_FOLD_LOG_CAPACITY` folds, keyed by `fold_count % ENTROPY_FOLD_LOG_CAPACITY`.
+pub const ENTROPY_FOLD_SENDERS: Map<u64, Addr> = Map::new("entropy_fold_senders");